@@ -1,11 +1,19 @@
-use bevy_ecs::{prelude::*, schedule::ScheduleLabel};
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ExecutorKind, InternedScheduleLabel, IntoSystemSetConfigs, ScheduleLabel, SystemSet},
+};
 use godot::prelude::*;
+use std::any::TypeId;
+use std::collections::{HashMap, HashSet};
 
 #[derive(GodotClass)]
 #[class(base=Node)]
 struct Ecs {
     pub world: World,
     pub schedule_order: ScheduleOrder,
+    plugin_registry: Vec<Box<dyn Plugin>>,
+    state_depth: HashMap<TypeId, usize>,
+    non_send_schedules: HashSet<InternedScheduleLabel>,
 
     #[base]
     node: Base<Node>,
@@ -22,22 +30,35 @@ impl INode for Ecs {
         let input = InputSingleton(Input::singleton());
         world.insert_non_send_resource(input);
 
-        Self {
+        let mut ecs = Self {
             world,
             schedule_order: ScheduleOrder::default(),
+            plugin_registry: Vec::new(),
+            state_depth: HashMap::new(),
+            non_send_schedules: HashSet::new(),
             node,
-        }
+        };
+
+        // The built-in `Process` schedule's escape-key system reads `NonSend`
+        // Godot resources directly, rather than through `add_systems`.
+        ecs.refresh_non_send_tracking(Process.intern());
 
-        // Use configure functions for separate modules?
-        // main_world::configure(&mut ecs);
-        // combat::configure(&mut ecs);
-        // pause_ui::configure(&mut ecs);
+        // Feature modules register themselves as `Plugin`s instead of being
+        // hand-wired here:
+        // ecs.add_plugins((main_world::MainWorldPlugin, combat::CombatPlugin, pause_ui::PauseUiPlugin));
 
-        // ecs
+        ecs
     }
 
-    //## remove if unused
-    fn ready(&mut self) {}
+    fn ready(&mut self) {
+        // `init` only constructs this node; a caller can still call
+        // `add_plugins` on it (e.g. right after `Ecs::new_alloc()`, before
+        // `add_child` puts it in the scene tree) up until `ready` fires.
+        // Running `finish_plugins` here instead of at the end of `init`
+        // leaves that whole window open for registering plugins before
+        // `finish` treats the set as closed.
+        self.finish_plugins();
+    }
 
     fn process(&mut self, delta: f64) {
         let mut process_delta = self.world.resource_mut();
@@ -63,6 +84,8 @@ impl Ecs {
         let mut world = World::new();
         world.init_resource::<ProcessDelta>();
         world.init_resource::<PhysicsDelta>();
+        world.init_resource::<PluginsState>();
+        world.init_resource::<EventUpdateSignal>();
         world.insert_resource(Self::schedules());
 
         world
@@ -72,6 +95,7 @@ impl Ecs {
         let mut schedules = Schedules::new();
 
         let mut process = Schedule::new(Process);
+        process.set_executor_kind(ExecutorKind::SingleThreaded);
         process.add_systems(
             |input_res: NonSend<InputSingleton>, ecs_res: NonSend<EcsNode>| {
                 let input = &input_res.0;
@@ -89,6 +113,7 @@ impl Ecs {
         schedules.insert(process);
 
         let mut post_physics = Schedule::new(PostPhysics);
+        post_physics.set_executor_kind(ExecutorKind::SingleThreaded);
         // Signal to clear events after main physics systems have had a chance to process them
         post_physics.add_systems(event_queue_update_system);
         schedules.insert(post_physics);
@@ -103,10 +128,15 @@ impl Ecs {
         if !self.world.contains_resource::<Events<T>>() {
             self.world.init_resource::<Events<T>>();
 
-            self.add_systems(
-                PreProcess,
-                event_update_system::<T>.run_if(bevy_ecs::event::event_update_condition::<T>),
-            );
+            if !self.world.contains_resource::<EventRegistry>() {
+                self.world.init_resource::<EventRegistry>();
+                self.add_systems(PreProcess, event_update_system);
+            }
+
+            self.world
+                .resource_mut::<EventRegistry>()
+                .update_fns
+                .push(update_events::<T>);
         }
 
         self
@@ -119,12 +149,197 @@ impl Ecs {
     ) -> &mut Self {
         let schedule = schedule.intern();
 
+        {
+            let mut schedules = self.world.resource_mut::<Schedules>();
+            if let Some(existing) = schedules.get_mut(schedule) {
+                existing.add_systems(systems);
+            } else {
+                let mut new_schedule = Schedule::new(schedule);
+                // Godot resources like `EcsNode`/`InputSingleton` are non-send, so
+                // every schedule defaults to single-threaded until a plugin opts
+                // it into `ExecutorKind::MultiThreaded` via `Ecs::set_executor`.
+                new_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+                new_schedule.add_systems(systems);
+                schedules.insert(new_schedule);
+            }
+        }
+
+        self.refresh_non_send_tracking(schedule);
+
+        self
+    }
+
+    /// Registers one plugin, or a tuple of plugins, building each immediately.
+    pub fn add_plugins<M>(&mut self, plugins: impl Plugins<M>) -> &mut Self {
+        plugins.add_to_ecs(self);
+        self
+    }
+
+    fn add_boxed_plugin(&mut self, plugin: Box<dyn Plugin>) {
+        plugin.build(self);
+        self.plugin_registry.push(plugin);
+    }
+
+    /// Runs each registered plugin's [`Plugin::finish`] exactly once.
+    ///
+    /// This happens after every plugin's `build` has inserted its systems and
+    /// resources, so a plugin can rely on the `World` (and any non-send Godot
+    /// resources other plugins inserted during `build`) being fully set up.
+    ///
+    /// A `finish` that itself calls `add_plugins` registers (and `build`s)
+    /// the new plugin immediately, but its `finish` wouldn't otherwise run
+    /// until the *next* `finish_plugins` call — so this keeps sweeping
+    /// through newly-registered plugins (and only those) until a pass adds
+    /// none.
+    fn finish_plugins(&mut self) {
+        if *self.world.resource::<PluginsState>() == PluginsState::Finished {
+            return;
+        }
+
+        let mut finished = Vec::new();
+        let mut pending = std::mem::take(&mut self.plugin_registry);
+        while !pending.is_empty() {
+            for plugin in pending.iter() {
+                plugin.finish(self);
+            }
+            finished.append(&mut pending);
+
+            // `add_boxed_plugin` pushes onto `self.plugin_registry`, so any
+            // plugin registered from within the `finish` calls above ends up
+            // here, ready for its own `finish` next pass.
+            pending = std::mem::take(&mut self.plugin_registry);
+        }
+
+        self.plugin_registry = finished;
+        *self.world.resource_mut::<PluginsState>() = PluginsState::Finished;
+    }
+
+    /// Inserts `schedule` into the process/physics order immediately after
+    /// `after`, wherever `after` currently sits.
+    pub fn insert_schedule_after(
+        &mut self,
+        after: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.schedule_order
+            .insert_after(after.intern(), schedule.intern());
+        self
+    }
+
+    /// Inserts `schedule` into the process/physics order immediately before
+    /// `before`, wherever `before` currently sits.
+    pub fn insert_schedule_before(
+        &mut self,
+        before: impl ScheduleLabel,
+        schedule: impl ScheduleLabel,
+    ) -> &mut Self {
+        self.schedule_order
+            .insert_before(before.intern(), schedule.intern());
+        self
+    }
+
+    /// Appends `schedule` to the end of the `process` order, run every
+    /// `_process` callback after every schedule already registered.
+    pub fn push_process_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.schedule_order.process.push(schedule.intern());
+        self
+    }
+
+    /// Appends `schedule` to the end of the `physics` order, run every
+    /// `_physics_process` callback after every schedule already registered.
+    pub fn push_physics_schedule(&mut self, schedule: impl ScheduleLabel) -> &mut Self {
+        self.schedule_order.physics.push(schedule.intern());
+        self
+    }
+
+    /// The schedules run by `_process`, in order.
+    pub fn process_order(&self) -> &[InternedScheduleLabel] {
+        &self.schedule_order.process
+    }
+
+    /// The schedules run by `_physics_process`, in order.
+    pub fn physics_order(&self) -> &[InternedScheduleLabel] {
+        &self.schedule_order.physics
+    }
+
+    /// Selects the executor a schedule runs under.
+    ///
+    /// Every schedule defaults to [`ExecutorKind::SingleThreaded`], because
+    /// any schedule containing a `NonSend` system param (anything touching
+    /// `EcsNode`/`InputSingleton` or other Godot handles) must stay on the
+    /// single thread that owns the `World`. CPU-heavy schedules with no
+    /// `NonSend` systems (pathfinding, AI, combat resolution) can opt into
+    /// [`ExecutorKind::MultiThreaded`] here. Refuses the switch for a
+    /// schedule this `Ecs` already knows touches non-send resources.
+    pub fn set_executor(&mut self, schedule: impl ScheduleLabel, kind: ExecutorKind) -> &mut Self {
+        let label = schedule.intern();
+
+        if kind != ExecutorKind::SingleThreaded && self.non_send_schedules.contains(&label) {
+            godot_warn!(
+                "refusing to switch {label:?} to a multi-threaded executor: it contains a NonSend system and must stay single-threaded",
+            );
+            return self;
+        }
+
+        let mut schedules = self.world.resource_mut::<Schedules>();
+        if let Some(schedule) = schedules.get_mut(label) {
+            schedule.set_executor_kind(kind);
+        }
+
+        self
+    }
+
+    /// Initializes `schedule` against the `World` and records whether any of
+    /// its systems are non-send, so [`Ecs::set_executor`] knows to refuse a
+    /// multi-threaded executor for it. Called automatically by
+    /// [`Ecs::add_systems`] whenever systems are added to a schedule.
+    ///
+    /// If this is the first time `schedule` is seen to touch a non-send
+    /// resource, it's forced back to [`ExecutorKind::SingleThreaded`]
+    /// immediately — a prior `set_executor` call may have already switched it
+    /// to multi-threaded before this non-send system was added, and
+    /// `set_executor`'s own refusal check only protects *future* calls.
+    fn refresh_non_send_tracking(&mut self, schedule: InternedScheduleLabel) {
+        let touches_non_send = self
+            .world
+            .try_schedule_scope(schedule, |world, schedule| {
+                schedule.initialize(world).ok();
+                schedule
+                    .systems()
+                    .map(|mut systems| systems.any(|system| !system.is_send()))
+                    .unwrap_or(false)
+            })
+            .unwrap_or(false);
+
+        if touches_non_send && self.non_send_schedules.insert(schedule) {
+            let mut schedules = self.world.resource_mut::<Schedules>();
+            if let Some(existing) = schedules.get_mut(schedule) {
+                if existing.get_executor_kind() != ExecutorKind::SingleThreaded {
+                    godot_warn!(
+                        "{schedule:?} gained a NonSend system while running multi-threaded; forcing its executor back to SingleThreaded",
+                    );
+                }
+                existing.set_executor_kind(ExecutorKind::SingleThreaded);
+            }
+        }
+    }
+
+    pub fn configure_sets(
+        &mut self,
+        schedule: impl ScheduleLabel,
+        sets: impl IntoSystemSetConfigs,
+    ) -> &mut Self {
+        let schedule = schedule.intern();
+
         let mut schedules = self.world.resource_mut::<Schedules>();
         if let Some(schedule) = schedules.get_mut(schedule) {
-            schedule.add_systems(systems);
+            schedule.configure_sets(sets);
         } else {
             let mut new_schedule = Schedule::new(schedule);
-            new_schedule.add_systems(systems);
+            // Match `add_systems`'s default: stay single-threaded until a
+            // plugin explicitly opts in via `Ecs::set_executor`.
+            new_schedule.set_executor_kind(ExecutorKind::SingleThreaded);
+            new_schedule.configure_sets(sets);
             schedules.insert(new_schedule);
         }
 
@@ -140,22 +355,293 @@ impl Ecs {
 
             self.world.init_resource::<State<S>>();
             self.world.init_resource::<NextState<S>>();
+            self.state_depth.insert(TypeId::of::<S>(), 0);
 
             let state_systems = (
                 run_enter_schedule::<S>.run_if(run_once()),
                 apply_state_transition::<S>,
             );
 
-            self.add_systems(StateTransition, state_systems.chain());
+            self.add_systems(
+                StateTransition,
+                state_systems.chain().in_set(StateTransitionSteps(0)),
+            );
+        }
+
+        self
+    }
+
+    /// Registers a state that's derived from one or more source states
+    /// instead of being set directly, recomputing `S` via
+    /// [`ComputedStates::compute`] whenever a source changes.
+    pub fn init_computed_state<S>(&mut self) -> &mut Self
+    where
+        S: ComputedStates,
+    {
+        let type_id = TypeId::of::<S>();
+        if self.state_depth.contains_key(&type_id) {
+            return self;
+        }
+
+        if !S::SourceStates::all_registered(self) {
+            godot_warn!(
+                "init_computed_state::<{}>: registered before one of its source states; \
+                 it may be placed at the same StateTransitionSteps depth as that source and \
+                 read a stale value until registration order is fixed",
+                std::any::type_name::<S>(),
+            );
+        }
+
+        let depth = S::SourceStates::depth(self) + 1;
+        self.state_depth.insert(type_id, depth);
+
+        // Seed `NextState` with the initial value (if any); `State<S>` itself
+        // is only inserted once `apply_state_transition` runs, so its very
+        // first `OnEnter` fires like any other transition.
+        self.world.init_resource::<NextState<S>>();
+        let sources = S::SourceStates::read(&self.world);
+        if let Some(state) = S::compute(sources) {
+            self.world.resource_mut::<NextState<S>>().set(state);
+        }
+
+        let recompute_and_apply = (compute_state_transition::<S>, apply_state_transition::<S>)
+            .chain()
+            .in_set(StateTransitionSteps(depth));
+        self.add_systems(StateTransition, recompute_and_apply);
+
+        self.configure_sets(
+            StateTransition,
+            StateTransitionSteps(depth).after(StateTransitionSteps(depth - 1)),
+        );
+
+        self
+    }
+
+    /// Registers a state that only exists while its parent state matches a
+    /// given value, recomputing `S` via [`SubStates::compute`] whenever a
+    /// source changes.
+    pub fn init_sub_state<S>(&mut self) -> &mut Self
+    where
+        S: SubStates,
+    {
+        let type_id = TypeId::of::<S>();
+        if self.state_depth.contains_key(&type_id) {
+            return self;
+        }
+
+        if !S::SourceStates::all_registered(self) {
+            godot_warn!(
+                "init_sub_state::<{}>: registered before one of its source states; \
+                 it may be placed at the same StateTransitionSteps depth as that source and \
+                 read a stale value until registration order is fixed",
+                std::any::type_name::<S>(),
+            );
+        }
+
+        let depth = S::SourceStates::depth(self) + 1;
+        self.state_depth.insert(type_id, depth);
+
+        self.world.init_resource::<NextState<S>>();
+        let sources = S::SourceStates::read(&self.world);
+        if let Some(state) = S::compute(sources) {
+            self.world.resource_mut::<NextState<S>>().set(state);
         }
 
+        let recompute_and_apply = (compute_sub_state_transition::<S>, apply_state_transition::<S>)
+            .chain()
+            .in_set(StateTransitionSteps(depth));
+        self.add_systems(StateTransition, recompute_and_apply);
+
+        self.configure_sets(
+            StateTransition,
+            StateTransitionSteps(depth).after(StateTransitionSteps(depth - 1)),
+        );
+
         self
     }
 }
 
+#[derive(SystemSet, Hash, Eq, PartialEq, Clone, Debug)]
+struct StateTransitionSteps(usize);
+
+/// A source state (or tuple of source states) that a [`ComputedStates`] or
+/// [`SubStates`] is derived from.
+pub trait StateSet {
+    /// The current value of each source state, or `None` for a source that
+    /// isn't currently present (e.g. a [`SubStates`] that doesn't exist).
+    type Data;
+
+    fn read(world: &World) -> Self::Data;
+
+    fn depth(ecs: &Ecs) -> usize;
+
+    /// Whether every source state in this set has already been registered
+    /// via `init_state`/`init_computed_state`/`init_sub_state`. `depth`
+    /// can't tell an unregistered source from a registered one at depth 0,
+    /// so callers that need to distinguish the two (to warn about
+    /// registration order) use this instead.
+    fn all_registered(ecs: &Ecs) -> bool;
+}
+
+impl<S: States> StateSet for S {
+    type Data = Option<S>;
+
+    fn read(world: &World) -> Self::Data {
+        world.get_resource::<State<S>>().map(|state| state.get().clone())
+    }
+
+    fn depth(ecs: &Ecs) -> usize {
+        ecs.state_depth.get(&TypeId::of::<S>()).copied().unwrap_or(0)
+    }
+
+    fn all_registered(ecs: &Ecs) -> bool {
+        ecs.state_depth.contains_key(&TypeId::of::<S>())
+    }
+}
+
+macro_rules! impl_state_set_tuples {
+    ($(($S:ident)),+) => {
+        impl<$($S: States),+> StateSet for ($($S,)+) {
+            type Data = ($(Option<$S>,)+);
+
+            fn read(world: &World) -> Self::Data {
+                ($(<$S as StateSet>::read(world),)+)
+            }
+
+            fn depth(ecs: &Ecs) -> usize {
+                let mut depth = 0;
+                $(depth = depth.max(<$S as StateSet>::depth(ecs));)+
+                depth
+            }
+
+            fn all_registered(ecs: &Ecs) -> bool {
+                $(<$S as StateSet>::all_registered(ecs))&&+
+            }
+        }
+    };
+}
+
+impl_state_set_tuples!((S0), (S1));
+impl_state_set_tuples!((S0), (S1), (S2));
+
+/// A state derived from one or more source states via [`ComputedStates::compute`],
+/// recomputed each frame after its sources' `apply_state_transition` has run.
+pub trait ComputedStates: States {
+    type SourceStates: StateSet;
+
+    fn compute(sources: <Self::SourceStates as StateSet>::Data) -> Option<Self>;
+}
+
+/// A state that only exists while its source state(s) satisfy
+/// [`SubStates::compute`]; removed from the `World` otherwise.
+pub trait SubStates: States {
+    type SourceStates: StateSet;
+
+    fn compute(sources: <Self::SourceStates as StateSet>::Data) -> Option<Self>;
+}
+
+/// Applies a freshly (re)computed derived-state value, shared by
+/// [`compute_state_transition`] and [`compute_sub_state_transition`], which
+/// both read their sources via [`StateSet::read`] and call `compute` the same
+/// way.
+fn apply_computed_state<S: States>(world: &mut World, computed: Option<S>) {
+    match computed {
+        Some(next) => {
+            if !world.contains_resource::<NextState<S>>() {
+                world.init_resource::<NextState<S>>();
+            }
+            // `State<S>` itself is inserted by `apply_state_transition`, so a
+            // freshly-(re)computed state's first frame still fires `OnEnter`.
+            world.resource_mut::<NextState<S>>().set(next);
+        }
+        None => {
+            world.remove_resource::<State<S>>();
+            world.remove_resource::<NextState<S>>();
+        }
+    }
+}
+
+fn compute_state_transition<S: ComputedStates>(world: &mut World) {
+    let sources = S::SourceStates::read(world);
+    let computed = S::compute(sources);
+    apply_computed_state(world, computed);
+}
+
+fn compute_sub_state_transition<S: SubStates>(world: &mut World) {
+    let sources = S::SourceStates::read(world);
+    let computed = S::compute(sources);
+    apply_computed_state(world, computed);
+}
+
+/// A self-contained unit of `Ecs` configuration: a feature module implements
+/// this instead of being hand-wired into [`Ecs::schedules`].
+///
+/// Mirrors `bevy_app`'s `Plugin` trait: `build` registers systems, events and
+/// states, while `finish` runs afterwards once every plugin has had a chance
+/// to `build`, for setup that depends on another plugin's resources.
+pub trait Plugin: 'static {
+    fn build(&self, ecs: &mut Ecs);
+
+    fn finish(&self, _ecs: &mut Ecs) {}
+}
+
+/// Implemented for a single [`Plugin`] and for tuples of types that are
+/// themselves `Plugins`, so [`Ecs::add_plugins`] accepts either.
+pub trait Plugins<Marker> {
+    fn add_to_ecs(self, ecs: &mut Ecs);
+}
+
+#[doc(hidden)]
+pub struct PluginMarker;
+
+impl<P: Plugin> Plugins<PluginMarker> for P {
+    fn add_to_ecs(self, ecs: &mut Ecs) {
+        ecs.add_boxed_plugin(Box::new(self));
+    }
+}
+
+#[doc(hidden)]
+pub struct PluginsTupleMarker;
+
+macro_rules! impl_plugins_tuples {
+    ($(($P:ident, $M:ident)),+) => {
+        impl<$($P, $M),+> Plugins<(PluginsTupleMarker, $($M,)+)> for ($($P,)+)
+        where
+            $($P: Plugins<$M>),+
+        {
+            #[allow(non_snake_case)]
+            fn add_to_ecs(self, ecs: &mut Ecs) {
+                let ($($P,)+) = self;
+                $($P.add_to_ecs(ecs);)+
+            }
+        }
+    };
+}
+
+impl_plugins_tuples!((P0, M0));
+impl_plugins_tuples!((P0, M0), (P1, M1));
+impl_plugins_tuples!((P0, M0), (P1, M1), (P2, M2));
+impl_plugins_tuples!((P0, M0), (P1, M1), (P2, M2), (P3, M3));
+impl_plugins_tuples!((P0, M0), (P1, M1), (P2, M2), (P3, M3), (P4, M4));
+impl_plugins_tuples!(
+    (P0, M0),
+    (P1, M1),
+    (P2, M2),
+    (P3, M3),
+    (P4, M4),
+    (P5, M5)
+);
+
+#[derive(Resource, Default, PartialEq, Eq, Clone, Copy, Debug)]
+enum PluginsState {
+    #[default]
+    Adding,
+    Finished,
+}
+
 struct ScheduleOrder {
-    process: Vec<bevy_ecs::schedule::InternedScheduleLabel>,
-    physics: Vec<bevy_ecs::schedule::InternedScheduleLabel>,
+    process: Vec<InternedScheduleLabel>,
+    physics: Vec<InternedScheduleLabel>,
 }
 impl Default for ScheduleOrder {
     fn default() -> Self {
@@ -169,6 +655,39 @@ impl Default for ScheduleOrder {
         }
     }
 }
+impl ScheduleOrder {
+    /// Inserts `schedule` into whichever of `process`/`physics` contains
+    /// `after`, immediately following it. Warns and drops `schedule` if
+    /// `after` isn't currently in either list.
+    fn insert_after(&mut self, after: InternedScheduleLabel, schedule: InternedScheduleLabel) {
+        for labels in [&mut self.process, &mut self.physics] {
+            if let Some(index) = labels.iter().position(|label| *label == after) {
+                labels.insert(index + 1, schedule);
+                return;
+            }
+        }
+
+        godot_warn!(
+            "insert_schedule_after: anchor {after:?} not found in process/physics order; {schedule:?} was not inserted",
+        );
+    }
+
+    /// Inserts `schedule` into whichever of `process`/`physics` contains
+    /// `before`, immediately preceding it. Warns and drops `schedule` if
+    /// `before` isn't currently in either list.
+    fn insert_before(&mut self, before: InternedScheduleLabel, schedule: InternedScheduleLabel) {
+        for labels in [&mut self.process, &mut self.physics] {
+            if let Some(index) = labels.iter().position(|label| *label == before) {
+                labels.insert(index, schedule);
+                return;
+            }
+        }
+
+        godot_warn!(
+            "insert_schedule_before: anchor {before:?} not found in process/physics order; {schedule:?} was not inserted",
+        );
+    }
+}
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Clone, Copy, Debug)]
 struct StateTransition;
@@ -206,18 +725,44 @@ pub fn event_queue_update_system(signal: Option<ResMut<EventUpdateSignal>>) {
     }
 }
 
-/// A system that calls [`Events::update`].
-pub fn event_update_system<T: Event>(
-    signal: Option<ResMut<EventUpdateSignal>>,
-    mut events: ResMut<Events<T>>,
-) {
-    if let Some(mut s) = signal {
-        // If we haven't got a signal to update the events, but we *could* get such a signal
-        // return early and update the events later.
-        if !std::mem::replace(&mut s.0, false) {
-            return;
-        }
+/// One type-erased `Events::<T>::update` call per event type registered via
+/// [`Ecs::add_event`], walked by the single [`event_update_system`] instead
+/// of scheduling a system per event type.
+#[derive(Resource, Default)]
+struct EventRegistry {
+    update_fns: Vec<fn(&mut World)>,
+}
+
+fn update_events<T: Event>(world: &mut World) {
+    if let Some(mut events) = world.get_resource_mut::<Events<T>>() {
+        events.update();
+    }
+}
+
+/// A system that calls [`Events::update`] for every registered event type.
+fn event_update_system(world: &mut World) {
+    let should_update = world
+        .get_resource_mut::<EventUpdateSignal>()
+        .map(|mut signal| std::mem::replace(&mut signal.0, false))
+        .unwrap_or(true);
+
+    // If we haven't got a signal to update the events, but we *could* get such a signal,
+    // return early and update the events later.
+    if !should_update {
+        return;
     }
 
-    events.update();
+    let update_fns = std::mem::take(&mut world.resource_mut::<EventRegistry>().update_fns);
+    for update_fn in &update_fns {
+        update_fn(world);
+    }
+    world.resource_mut::<EventRegistry>().update_fns = update_fns;
 }
+
+/// Re-exported so feature modules can write `add_systems(OnEnter(MyState::X), ...)`
+/// without reaching into `bevy_ecs` themselves. `apply_state_transition` (used
+/// by `init_state`/`init_computed_state`/`init_sub_state`) already dispatches
+/// these via `try_run_schedule`, and `add_systems` already creates schedules
+/// lazily the first time a system is added to them — states nobody hooks
+/// into never get an empty schedule.
+pub use bevy_ecs::schedule::{OnEnter, OnExit, OnTransition};